@@ -35,12 +35,38 @@ impl fmt::Display for BitString {
     }
 }
 
+#[derive(Clone, Debug)]
 struct Knapsack {
     weights: Vec<u16>,
     values: Vec<u16>,
     total_items: usize,
 }
 
+/// `solve_meet_in_the_middle` enumerates every subset of each half as a
+/// `(weight, value, mask)` tuple, so memory is the limiting factor rather
+/// than time: at 52 items, half = 26, so 2^26 (~67M) subsets per half is
+/// ~1.5GB of tuples, plus the sorted-by-weight copy and prefix-max vectors
+/// for the second half — a few GB total, the most a modern desktop should
+/// be asked to hold for this solver.
+const MAX_MEET_IN_THE_MIDDLE_SIZE: u8 = 52;
+
+/// Accumulated state threaded through `branch_and_bound_dfs`, bundled into
+/// one struct so the recursive search doesn't need a separate `&mut`
+/// parameter per tracked value.
+struct BranchAndBoundState<'a> {
+    order: &'a [usize],
+    knapsack_capacity: u64,
+    best_value: u64,
+    best_subset: BitString,
+    nodes_explored: u64,
+}
+
+/// Splits a line on commas and/or whitespace, dropping empty fields, so the
+/// instance format accepts both `weight value` and `weight,value` rows.
+fn split_fields(line: &str) -> impl Iterator<Item = &str> {
+    line.split(|c: char| c == ',' || c.is_whitespace()).filter(|field| !field.is_empty())
+}
+
 impl Knapsack {
     fn new(total_items: usize) -> Self {
         Self {
@@ -60,6 +86,66 @@ impl Knapsack {
         }
     }
 
+    /// Parses an instance from `reader`: a header line of `n capacity`,
+    /// followed by `n` lines of `weight value`. Fields may be separated by
+    /// whitespace or commas. Returns the knapsack alongside the capacity
+    /// read from the header, since capacity isn't stored on `Knapsack`
+    /// itself.
+    fn from_reader<R: std::io::BufRead>(reader: R) -> Result<(Self, u64), String> {
+        let mut lines = reader.lines();
+
+        let header = lines
+            .next()
+            .ok_or_else(|| "expected a header line of `n capacity`".to_string())?
+            .map_err(|err| err.to_string())?;
+        let mut header_fields = split_fields(&header);
+
+        let total_items: usize = header_fields
+            .next()
+            .ok_or_else(|| "missing item count in header".to_string())?
+            .parse()
+            .map_err(|_| "item count in header must be an integer".to_string())?;
+        let capacity: u64 = header_fields
+            .next()
+            .ok_or_else(|| "missing capacity in header".to_string())?
+            .parse()
+            .map_err(|_| "capacity in header must be an integer".to_string())?;
+
+        if total_items == 0 || total_items > 64 {
+            return Err(format!("item count {total_items} is outside the solver's supported range [1, 64]"));
+        }
+
+        let mut knapsack = Knapsack::new(total_items);
+        for i in 0..total_items {
+            let line = lines
+                .next()
+                .ok_or_else(|| format!("expected {total_items} item lines, found {i}"))?
+                .map_err(|err| err.to_string())?;
+            let mut fields = split_fields(&line);
+
+            knapsack.weights[i] = fields
+                .next()
+                .ok_or_else(|| format!("missing weight on item line {i}"))?
+                .parse()
+                .map_err(|_| format!("weight on item line {i} must be an integer"))?;
+            knapsack.values[i] = fields
+                .next()
+                .ok_or_else(|| format!("missing value on item line {i}"))?
+                .parse()
+                .map_err(|_| format!("value on item line {i} must be an integer"))?;
+        }
+
+        Ok((knapsack, capacity))
+    }
+
+    /// Convenience wrapper around `from_reader` that opens `path` and wraps
+    /// it in a buffered reader.
+    fn from_file<P: AsRef<std::path::Path>>(path: P) -> Result<(Self, u64), String> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path).map_err(|err| format!("failed to open {}: {err}", path.display()))?;
+        Self::from_reader(std::io::BufReader::new(file))
+    }
+
     fn print_weights_and_values(&self) {
         #[derive(Tabled)]
         struct Item {
@@ -159,17 +245,294 @@ impl Knapsack {
 
         return (best_subset, max_value)
     }
+
+    /// Item indices sorted by descending value/weight ratio, the greedy
+    /// order both `solve_branch_and_bound` and `solve_annealing` build on.
+    fn ratio_descending_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.total_items).collect();
+        // Zero-weight items have an unbounded ratio, so they sort first.
+        let ratio = |i: usize| {
+            if self.weights[i] == 0 {
+                f64::INFINITY
+            } else {
+                self.values[i] as f64 / self.weights[i] as f64
+            }
+        };
+        order.sort_by(|&a, &b| ratio(b).partial_cmp(&ratio(a)).unwrap_or(std::cmp::Ordering::Equal));
+        order
+    }
+
+    /// Exact solver that prunes the search tree instead of enumerating every
+    /// subset. Items are visited in descending value/weight ratio order, and
+    /// at each node a fractional-knapsack upper bound on the remaining items
+    /// decides whether the subtree can possibly beat the best integral value
+    /// found so far. Returns the best subset, its value, and the number of
+    /// nodes explored so callers can compare pruning effectiveness against
+    /// `solve`.
+    fn solve_branch_and_bound(&self, knapsack_capacity: u64) -> (BitString, u64, u64) {
+        let order = self.ratio_descending_order();
+
+        let mut state = BranchAndBoundState {
+            order: &order,
+            knapsack_capacity,
+            best_value: 0,
+            best_subset: BitString::new(0),
+            nodes_explored: 0,
+        };
+
+        self.branch_and_bound_dfs(0, 0, 0, BitString::new(0), &mut state);
+
+        (state.best_subset, state.best_value, state.nodes_explored)
+    }
+
+    /// Optimistic upper bound on the value reachable from `order[start..]`
+    /// given `current_value` already locked in and `current_weight` already
+    /// spent: greedily take whole items in ratio order until the next one
+    /// would overflow the remaining capacity, then add its fractional value.
+    fn upper_bound(
+        &self,
+        order: &[usize],
+        start: usize,
+        current_weight: u64,
+        current_value: u64,
+        knapsack_capacity: u64,
+    ) -> f64 {
+        let mut remaining_capacity = knapsack_capacity.saturating_sub(current_weight) as f64;
+        let mut bound = current_value as f64;
+
+        for &idx in &order[start..] {
+            let weight = self.weights[idx] as f64;
+            let value = self.values[idx] as f64;
+
+            if weight <= remaining_capacity {
+                remaining_capacity -= weight;
+                bound += value;
+            } else {
+                if remaining_capacity > 0.0 {
+                    bound += value * remaining_capacity / weight;
+                }
+                break;
+            }
+        }
+
+        bound
+    }
+
+    fn branch_and_bound_dfs(
+        &self,
+        depth: usize,
+        current_weight: u64,
+        current_value: u64,
+        bit_str: BitString,
+        state: &mut BranchAndBoundState<'_>,
+    ) {
+        state.nodes_explored += 1;
+
+        if current_value > state.best_value {
+            state.best_value = current_value;
+            state.best_subset = bit_str;
+        }
+
+        if depth == state.order.len() {
+            return;
+        }
+
+        if self.upper_bound(state.order, depth, current_weight, current_value, state.knapsack_capacity) <= state.best_value as f64 {
+            return;
+        }
+
+        let idx = state.order[depth];
+        let weight = self.weights[idx] as u64;
+        let value = self.values[idx] as u64;
+
+        if current_weight + weight <= state.knapsack_capacity {
+            let mut included = bit_str;
+            included.flip_bit(idx);
+            self.branch_and_bound_dfs(depth + 1, current_weight + weight, current_value + value, included, state);
+        }
+
+        self.branch_and_bound_dfs(depth + 1, current_weight, current_value, bit_str, state);
+    }
+
+    /// Exact solver that splits the items into two halves, enumerates every
+    /// subset of each half, and combines them in roughly 2^(n/2) time instead
+    /// of 2^n. Subsets of the second half are sorted by weight with a
+    /// running prefix-maximum over value, so the best complement for any
+    /// remaining capacity budget is a binary search away.
+    fn solve_meet_in_the_middle(&self, knapsack_capacity: u64) -> (BitString, u64) {
+        let half = self.total_items.div_ceil(2);
+        let a_indices: Vec<usize> = (0..half).collect();
+        let b_indices: Vec<usize> = (half..self.total_items).collect();
+
+        let enumerate_half = |indices: &[usize]| -> Vec<(u64, u64, u64)> {
+            let mut subsets = Vec::with_capacity(1 << indices.len());
+            for mask in 0..(1u64 << indices.len()) {
+                let mut weight = 0u64;
+                let mut value = 0u64;
+                let mut global_mask = 0u64;
+                for (bit, &idx) in indices.iter().enumerate() {
+                    if mask & (1 << bit) != 0 {
+                        weight += self.weights[idx] as u64;
+                        value += self.values[idx] as u64;
+                        global_mask |= 1u64 << idx;
+                    }
+                }
+                subsets.push((weight, value, global_mask));
+            }
+            subsets
+        };
+
+        let subsets_a = enumerate_half(&a_indices);
+        let mut subsets_b = enumerate_half(&b_indices);
+        subsets_b.sort_by_key(|&(weight, _, _)| weight);
+
+        // Prefix-maximum over value, keyed by the sorted weight so the best
+        // B subset at or below any budget is a single binary search away.
+        let mut prefix_weights = Vec::with_capacity(subsets_b.len());
+        let mut prefix_best = Vec::with_capacity(subsets_b.len());
+        let mut best_so_far = (0u64, 0u64);
+        for &(weight, value, mask) in &subsets_b {
+            if value > best_so_far.0 {
+                best_so_far = (value, mask);
+            }
+            prefix_weights.push(weight);
+            prefix_best.push(best_so_far);
+        }
+
+        let mut best_value = 0u64;
+        let mut best_mask = 0u64;
+
+        for &(weight_a, value_a, mask_a) in &subsets_a {
+            if weight_a > knapsack_capacity {
+                continue;
+            }
+
+            let budget = knapsack_capacity - weight_a;
+            let count = prefix_weights.partition_point(|&weight| weight <= budget);
+            let (value_b, mask_b) = if count > 0 { prefix_best[count - 1] } else { (0, 0) };
+
+            let total_value = value_a + value_b;
+            if total_value > best_value {
+                best_value = total_value;
+                best_mask = mask_a | mask_b;
+            }
+        }
+
+        (BitString::new(best_mask), best_value)
+    }
+
+    /// Anytime heuristic for when even meet-in-the-middle is too slow:
+    /// starts from a greedy ratio-ordered feasible solution, then until
+    /// `time_limit` elapses repeatedly flips a random item, accepting the
+    /// move if it improves value or, if it worsens value by `delta`, with
+    /// probability `exp(-delta / temperature)`. Temperature decays
+    /// geometrically from a starting value toward near-zero as the deadline
+    /// approaches. Returns the best feasible subset/value seen across the
+    /// whole run; there's no guarantee of optimality.
+    fn solve_annealing(&self, knapsack_capacity: u64, time_limit: std::time::Duration) -> (BitString, u64) {
+        let start = Instant::now();
+        let deadline = start + time_limit;
+
+        let order = self.ratio_descending_order();
+
+        let mut current = BitString::new(0);
+        let mut current_weight = 0u64;
+        let mut current_value = 0u64;
+        for &idx in &order {
+            let weight = self.weights[idx] as u64;
+            if current_weight + weight <= knapsack_capacity {
+                current.flip_bit(idx);
+                current_weight += weight;
+                current_value += self.values[idx] as u64;
+            }
+        }
+
+        let mut best = current;
+        let mut best_value = current_value;
+
+        if self.total_items == 0 {
+            return (best, best_value);
+        }
+
+        const START_TEMPERATURE: f64 = 100.0;
+        const END_TEMPERATURE: f64 = 0.001;
+
+        let mut rng = rand::thread_rng();
+        let total_secs = time_limit.as_secs_f64().max(f64::MIN_POSITIVE);
+
+        while Instant::now() < deadline {
+            let remaining_secs = deadline.saturating_duration_since(Instant::now()).as_secs_f64();
+            let progress = (1.0 - remaining_secs / total_secs).clamp(0.0, 1.0);
+            let temperature = START_TEMPERATURE * (END_TEMPERATURE / START_TEMPERATURE).powf(progress);
+
+            let idx = rng.gen_range(0..self.total_items);
+            let weight = self.weights[idx] as u64;
+            let value = self.values[idx] as u64;
+
+            let (candidate_weight, candidate_value) = if current.is_bit_set(idx) {
+                (current_weight - weight, current_value - value)
+            } else {
+                (current_weight + weight, current_value + value)
+            };
+
+            if candidate_weight > knapsack_capacity {
+                continue;
+            }
+
+            let delta = candidate_value as f64 - current_value as f64;
+            let accept = delta >= 0.0 || rng.gen::<f64>() < (delta / temperature).exp();
+
+            if accept {
+                current.flip_bit(idx);
+                current_weight = candidate_weight;
+                current_value = candidate_value;
+
+                if current_value > best_value {
+                    best_value = current_value;
+                    best = current;
+                }
+            }
+        }
+
+        (best, best_value)
+    }
 }
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+enum Solver {
+    /// Exhaustive Gray-code enumeration, capped at 50 items
+    BruteForce,
+    /// Branch-and-bound with fractional-knapsack pruning
+    BranchAndBound,
+    /// Meet-in-the-middle over two enumerated halves, roughly 2^(n/2), capped at MAX_MEET_IN_THE_MIDDLE_SIZE items
+    MeetInTheMiddle,
+    /// Simulated annealing within a --time-limit-ms wall-clock budget
+    Annealing,
+}
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
-    /// Number of total items must be in the range [1, 50]
-    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=50))]
+    /// Number of total items. Must be in [1, 50] for --solver brute-force,
+    /// in [1, 52] for --solver meet-in-the-middle, and in [1, 64] for the
+    /// other solvers.
+    /// Ignored (and overridden) when --input is given.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u8).range(1..=64))]
     size: u8,
 
+    /// Which algorithm to run each trial
+    #[arg(long, value_enum, default_value_t = Solver::BruteForce)]
+    solver: Solver,
+
+    /// Load a fixed instance from a file instead of generating one:
+    /// first line `n capacity`, then `n` lines of `weight value`
+    /// (whitespace- or comma-separated). Overrides --size and
+    /// --knapsack-capacity.
+    #[arg(long)]
+    input: Option<std::path::PathBuf>,
+
     /// Number of times to run the experiment
     #[arg(long, default_value_t = 3)]
     trials: usize,
@@ -197,6 +560,10 @@ struct Args {
     /// Knapsack Capacity
     #[arg(long, default_value_t = 1000)]
     knapsack_capacity: u64,
+
+    /// Wall-clock budget for --solver annealing, in milliseconds
+    #[arg(long, default_value_t = 1000)]
+    time_limit_ms: u64,
 }
 
 use rayon::prelude::*;
@@ -205,22 +572,85 @@ use std::sync::{Arc, Mutex};
 fn main() {
     let args = Args::parse();
 
+    let loaded = args.input.as_ref().map(|path| {
+        Knapsack::from_file(path).unwrap_or_else(|err| {
+            eprintln!("failed to load --input {}: {err}", path.display());
+            std::process::exit(1);
+        })
+    });
+
+    let size = loaded.as_ref().map(|(knapsack, _)| knapsack.total_items as u8).unwrap_or(args.size);
+    let knapsack_capacity = loaded.as_ref().map(|(_, capacity)| *capacity).unwrap_or(args.knapsack_capacity);
+
+    if matches!(args.solver, Solver::BruteForce) && size > 50 {
+        eprintln!("--size is limited to 50 for --solver brute-force; pick --solver branch-and-bound for larger instances");
+        std::process::exit(1);
+    }
+
+    if matches!(args.solver, Solver::MeetInTheMiddle) && size > MAX_MEET_IN_THE_MIDDLE_SIZE {
+        eprintln!(
+            "--size is limited to {MAX_MEET_IN_THE_MIDDLE_SIZE} for --solver meet-in-the-middle; \
+             each half enumerates 2^(size/2) subsets in memory and larger instances would exhaust it"
+        );
+        std::process::exit(1);
+    }
+
     let trial_results = Arc::new(Mutex::new(vec![]));
     let multiprogress = MultiProgress::new();
     let print_lock = Arc::new(Mutex::new(()));
 
 
     (0..args.trials).into_par_iter().for_each(|i| {
-        let mut knapsack = Knapsack::new(args.size as usize);
+        let mut knapsack = match &loaded {
+            Some((knapsack, _)) => knapsack.clone(),
+            None => Knapsack::new(size as usize),
+        };
 
         let trial_results = trial_results.clone();
         let print_lock = print_lock.clone();
 
-        knapsack.initialize_values(args.weight_min..=args.weight_max, args.value_min..=args.value_max);
+        if loaded.is_none() {
+            knapsack.initialize_values(args.weight_min..=args.weight_max, args.value_min..=args.value_max);
+        }
 
-        let now = Instant::now();
-        let (subset, value) = knapsack.solve(args.knapsack_capacity, multiprogress.clone(), args.update_freq);
-        let elapsed = now.elapsed().as_secs_f64();
+        // Each arm times only its own primary solver call; the Annealing arm's
+        // follow-up exact-answer comparison is timed separately so it doesn't
+        // inflate the trial's reported/averaged elapsed time.
+        let (subset, value, nodes_explored, exact_comparison, elapsed) = match args.solver {
+            Solver::BruteForce => {
+                let start = Instant::now();
+                let (subset, value) = knapsack.solve(knapsack_capacity, multiprogress.clone(), args.update_freq);
+                (subset, value, None, None, start.elapsed().as_secs_f64())
+            }
+            Solver::BranchAndBound => {
+                let start = Instant::now();
+                let (subset, value, nodes_explored) = knapsack.solve_branch_and_bound(knapsack_capacity);
+                (subset, value, Some(nodes_explored), None, start.elapsed().as_secs_f64())
+            }
+            Solver::MeetInTheMiddle => {
+                let start = Instant::now();
+                let (subset, value) = knapsack.solve_meet_in_the_middle(knapsack_capacity);
+                (subset, value, None, None, start.elapsed().as_secs_f64())
+            }
+            Solver::Annealing => {
+                let annealing_start = Instant::now();
+                let (subset, value) = knapsack.solve_annealing(knapsack_capacity, std::time::Duration::from_millis(args.time_limit_ms));
+                let annealing_elapsed = annealing_start.elapsed().as_secs_f64();
+
+                // The exact answer is only cheap to compute for sizes brute
+                // force can still handle; skip the comparison otherwise.
+                let exact_comparison = if size <= 50 {
+                    let exact_start = Instant::now();
+                    let (_, exact_value) = knapsack.solve(knapsack_capacity, multiprogress.clone(), args.update_freq);
+                    let exact_elapsed = exact_start.elapsed().as_secs_f64();
+                    Some((exact_value, exact_elapsed, annealing_elapsed))
+                } else {
+                    None
+                };
+
+                (subset, value, None, exact_comparison, annealing_elapsed)
+            }
+        };
 
         trial_results.lock().unwrap().push(elapsed);
 
@@ -229,6 +659,17 @@ fn main() {
         println!("-------------------------------- TRIAL {i} --------------------------------");
         knapsack.print_weights_and_values();
         println!("Done! Took {elapsed} seconds");
+        if let Some(nodes_explored) = nodes_explored {
+            // size can be up to 64 now, and 1u64 << 64 overflows, so fall
+            // back to u64::MAX to describe "more than brute force could ever explore".
+            let brute_force_nodes = 1u64.checked_shl(size as u32).map(|n| n - 1).unwrap_or(u64::MAX);
+            println!("Nodes explored: {nodes_explored} (brute force would explore {brute_force_nodes})");
+        }
+        if let Some((exact_value, exact_elapsed, annealing_elapsed)) = exact_comparison {
+            let gap = exact_value.saturating_sub(value);
+            let time_saved = exact_elapsed - annealing_elapsed;
+            println!("Exact answer: {exact_value} (took {exact_elapsed} seconds); optimality gap: {gap}, time saved: {time_saved} seconds");
+        }
         println!("Best subset with value: {value} is");
         knapsack.print_best_subset(subset);
         println!("---------------------------------------------------------------------------");
@@ -238,3 +679,217 @@ fn main() {
     let trial_results = trial_results.lock().unwrap();
     println!("Took on average {}", trial_results.iter().sum::<f64>() / args.trials as f64);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Instance {
+        weights: Vec<u16>,
+        values: Vec<u16>,
+        capacity: u64,
+    }
+
+    fn random_instance(rng: &mut impl Rng) -> Instance {
+        let n = rng.gen_range(1..=12);
+        Instance {
+            weights: (0..n).map(|_| rng.gen_range(1..=20u16)).collect(),
+            values: (0..n).map(|_| rng.gen_range(1..=20u16)).collect(),
+            capacity: rng.gen_range(0..=100u64),
+        }
+    }
+
+    /// Independent O(n * capacity) dynamic program, used as the source of
+    /// truth that `solve` is checked against.
+    fn reference_optimum(weights: &[u16], values: &[u16], capacity: u64) -> u64 {
+        let capacity = capacity as usize;
+        let mut dp = vec![0u64; capacity + 1];
+        for i in 0..weights.len() {
+            let weight = weights[i] as usize;
+            let value = values[i] as u64;
+            for cap in (weight..=capacity).rev() {
+                dp[cap] = dp[cap].max(dp[cap - weight] + value);
+            }
+        }
+        dp[capacity]
+    }
+
+    /// Returns `None` if `solve` agrees with the reference optimum and its
+    /// returned subset respects the capacity and sums to the reported
+    /// value, or `Some(reason)` describing the mismatch otherwise.
+    fn check(instance: &Instance) -> Option<String> {
+        let n = instance.weights.len();
+        let mut knapsack = Knapsack::new(n);
+        knapsack.weights = instance.weights.clone();
+        knapsack.values = instance.values.clone();
+
+        let (subset, value) = knapsack.solve(instance.capacity, MultiProgress::new(), 1000);
+        let expected = reference_optimum(&instance.weights, &instance.values, instance.capacity);
+
+        if value != expected {
+            return Some(format!("solve returned {value}, reference optimum is {expected}"));
+        }
+
+        let mut weight_sum = 0u64;
+        let mut value_sum = 0u64;
+        for i in 0..n {
+            if subset.is_bit_set(i) {
+                weight_sum += instance.weights[i] as u64;
+                value_sum += instance.values[i] as u64;
+            }
+        }
+
+        if weight_sum > instance.capacity {
+            return Some(format!("subset weight {weight_sum} exceeds capacity {}", instance.capacity));
+        }
+        if value_sum != value {
+            return Some(format!("subset value {value_sum} does not match reported value {value}"));
+        }
+
+        None
+    }
+
+    /// Tries each of: dropping the last item, halving every weight, halving
+    /// every value, and halving the capacity. Returns the first reduction
+    /// that still reproduces a failure, or `None` if nothing shrinks further.
+    fn try_shrink_once(instance: &Instance) -> Option<Instance> {
+        if !instance.weights.is_empty() {
+            let candidate = Instance {
+                weights: instance.weights[..instance.weights.len() - 1].to_vec(),
+                values: instance.values[..instance.values.len() - 1].to_vec(),
+                capacity: instance.capacity,
+            };
+            if check(&candidate).is_some() {
+                return Some(candidate);
+            }
+        }
+
+        if instance.weights.iter().any(|&w| w > 1) {
+            let candidate = Instance {
+                weights: instance.weights.iter().map(|&w| (w / 2).max(1)).collect(),
+                values: instance.values.clone(),
+                capacity: instance.capacity,
+            };
+            if check(&candidate).is_some() {
+                return Some(candidate);
+            }
+        }
+
+        if instance.values.iter().any(|&v| v > 1) {
+            let candidate = Instance {
+                weights: instance.weights.clone(),
+                values: instance.values.iter().map(|&v| (v / 2).max(1)).collect(),
+                capacity: instance.capacity,
+            };
+            if check(&candidate).is_some() {
+                return Some(candidate);
+            }
+        }
+
+        if instance.capacity > 0 {
+            let candidate = Instance {
+                weights: instance.weights.clone(),
+                values: instance.values.clone(),
+                capacity: instance.capacity / 2,
+            };
+            if check(&candidate).is_some() {
+                return Some(candidate);
+            }
+        }
+
+        None
+    }
+
+    fn shrink(mut instance: Instance) -> Instance {
+        while let Some(reduced) = try_shrink_once(&instance) {
+            instance = reduced;
+        }
+        instance
+    }
+
+    #[test]
+    fn solve_matches_reference_dynamic_program() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let instance = random_instance(&mut rng);
+
+            if let Some(reason) = check(&instance) {
+                let minimal = shrink(instance);
+                panic!(
+                    "solve diverged from the reference optimum ({reason}); minimal reproducer: weights={:?} values={:?} capacity={}",
+                    minimal.weights, minimal.values, minimal.capacity
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_reader_parses_whitespace_separated_instance() {
+        let input = "3 10\n1 2\n3 4\n5 6\n";
+        let (knapsack, capacity) = Knapsack::from_reader(std::io::Cursor::new(input)).expect("should parse");
+
+        assert_eq!(capacity, 10);
+        assert_eq!(knapsack.total_items, 3);
+        assert_eq!(knapsack.weights, vec![1, 3, 5]);
+        assert_eq!(knapsack.values, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn from_reader_parses_comma_separated_instance() {
+        let input = "2,20\n4,5\n6,7\n";
+        let (knapsack, capacity) = Knapsack::from_reader(std::io::Cursor::new(input)).expect("should parse");
+
+        assert_eq!(capacity, 20);
+        assert_eq!(knapsack.weights, vec![4, 6]);
+        assert_eq!(knapsack.values, vec![5, 7]);
+    }
+
+    #[test]
+    fn from_reader_rejects_zero_items() {
+        let input = "0 10\n";
+        let err = Knapsack::from_reader(std::io::Cursor::new(input)).unwrap_err();
+        assert!(err.contains("outside the solver's supported range"));
+    }
+
+    #[test]
+    fn from_reader_rejects_too_many_items() {
+        let input = "65 10\n";
+        let err = Knapsack::from_reader(std::io::Cursor::new(input)).unwrap_err();
+        assert!(err.contains("outside the solver's supported range"));
+    }
+
+    #[test]
+    fn from_reader_rejects_malformed_header() {
+        let input = "not-a-number 10\n";
+        let err = Knapsack::from_reader(std::io::Cursor::new(input)).unwrap_err();
+        assert!(err.contains("item count in header must be an integer"));
+    }
+
+    #[test]
+    fn from_reader_rejects_missing_item_lines() {
+        let input = "2 10\n1 2\n";
+        let err = Knapsack::from_reader(std::io::Cursor::new(input)).unwrap_err();
+        assert!(err.contains("expected 2 item lines"));
+    }
+
+    #[test]
+    fn from_reader_rejects_malformed_item_line() {
+        let input = "1 10\nheavy light\n";
+        let err = Knapsack::from_reader(std::io::Cursor::new(input)).unwrap_err();
+        assert!(err.contains("weight on item line 0 must be an integer"));
+    }
+
+    #[test]
+    fn from_file_round_trips_a_written_instance() {
+        let path = std::env::temp_dir().join(format!("knapsack-from-file-test-{}.txt", std::process::id()));
+        std::fs::write(&path, "2 15\n3 9\n4 12\n").expect("should write temp instance file");
+
+        let (knapsack, capacity) = Knapsack::from_file(&path).expect("should parse file");
+        std::fs::remove_file(&path).expect("should clean up temp file");
+
+        assert_eq!(capacity, 15);
+        assert_eq!(knapsack.weights, vec![3, 4]);
+        assert_eq!(knapsack.values, vec![9, 12]);
+    }
+}